@@ -0,0 +1,105 @@
+use ide_assists::handlers::toggle_async_sugar::{resolve_impl_future_output, unwrap_async_body};
+use ide_db::{
+    assists::{Assist, AssistId, AssistKind},
+    source_change::SourceChange,
+    FileId,
+};
+use syntax::{ast, AstNode, TextRange};
+use text_edit::TextEdit;
+
+use crate::{Diagnostic, DiagnosticCode, DiagnosticsContext};
+
+// Diagnostic: manual-async-fn
+//
+// Fires on a non-`async` fn whose return type is a bare `impl Future<Output = T>` (no extra
+// bounds like `+ Send` to lose) and whose body is a single tail `async { ... }` block - the
+// exact shape `sugar_impl_future_into_async` rewrites. Reuses `resolve_impl_future_output`
+// (shared with that assist) to decide applicability, so the two can't drift apart on what
+// counts as "manual" async sugar; the fix performs the same rewrite the assist does.
+//
+// ```
+// # //- minicore: future
+// f$0n foo() -> impl core::future::Future<Output = usize> {
+//     async { 0 }
+// }
+// ```
+// ->
+// ```
+// async fn foo() -> usize {
+//     0
+// }
+// ```
+pub(crate) fn manual_async_fn(
+    ctx: &DiagnosticsContext<'_>,
+    file_id: FileId,
+    func: &ast::Fn,
+) -> Option<Diagnostic> {
+    if func.const_token().is_some() {
+        return None;
+    }
+
+    let (_, future_output, ret_type, has_extra_bounds) =
+        resolve_impl_future_output(&ctx.sema, func)?;
+    if has_extra_bounds {
+        return None;
+    }
+
+    // `unwrap_async_body` only returns `Some` for exactly this body shape, but checking here
+    // too keeps this diagnostic's applicability self-contained and readable.
+    let body = func.body()?;
+    if body.statements().next().is_some() {
+        return None;
+    }
+    let ast::Expr::BlockExpr(async_block) = body.tail_expr()? else {
+        return None;
+    };
+    if async_block.async_token().is_none() {
+        return None;
+    }
+
+    Some(
+        Diagnostic::new(
+            DiagnosticCode("manual-async-fn"),
+            "this function can be written as `async fn`",
+            ret_type.syntax().text_range(),
+        )
+        .with_fixes(fix(file_id, func, &future_output).into_iter().collect()),
+    )
+}
+
+fn fix(file_id: FileId, func: &ast::Fn, future_output: &ast::Type) -> Option<Assist> {
+    use syntax::ast::HasVisibility;
+
+    let ret_type = func.ret_type()?;
+
+    let mut edit = TextEdit::builder();
+    match future_output {
+        // `-> impl Future<Output = ()>` becomes no return type at all, as in the assist.
+        ast::Type::TupleType(t) if t.fields().next().is_none() => {
+            let end = ret_type.syntax().text_range().end();
+            let start = func.param_list().map_or(ret_type.syntax().text_range().start(), |params| {
+                params.syntax().text_range().end()
+            });
+            edit.delete(TextRange::new(start, end));
+        }
+        _ => edit.replace(
+            ret_type.ty()?.syntax().text_range(),
+            future_output.syntax().text().to_string(),
+        ),
+    }
+    let (place_for_async, async_kw) = match func.visibility() {
+        Some(vis) => (vis.syntax().text_range().end(), " async"),
+        None => (func.syntax().text_range().start(), "async "),
+    };
+    edit.insert(place_for_async, async_kw.to_owned());
+    if let Some((unwrap_range, unwrapped)) = unwrap_async_body(func) {
+        edit.replace(unwrap_range, unwrapped);
+    }
+
+    Some(Assist {
+        id: AssistId("sugar_impl_future_into_async", AssistKind::RefactorRewrite),
+        label: "Convert `impl Future` into async".to_owned(),
+        target: func.syntax().text_range(),
+        source_change: Some(SourceChange::from_text_edit(file_id, edit.finish())),
+    })
+}
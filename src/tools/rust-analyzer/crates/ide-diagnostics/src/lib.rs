@@ -0,0 +1,83 @@
+//! Computes diagnostics that IDEs can surface without a full compile: lints whose applicability
+//! can be decided from syntax plus light semantic analysis (trait/name resolution), each
+//! optionally carrying a quick-fix.
+//!
+//! This mirrors the shape of `ide-assists`: one handler module per diagnostic under `handlers/`,
+//! dispatched from [`diagnostics`] below.
+
+mod handlers {
+    pub(crate) mod manual_async_fn;
+}
+
+use hir::Semantics;
+use ide_db::{assists::Assist, FileId, RootDatabase};
+use syntax::{ast, AstNode, TextRange};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+    WeakWarning,
+    Allow,
+}
+
+/// Stable identifier for a diagnostic, used for `#[allow]`-style suppression and in tests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiagnosticCode(pub &'static str);
+
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub code: DiagnosticCode,
+    pub message: String,
+    pub range: TextRange,
+    pub severity: Severity,
+    pub fixes: Vec<Assist>,
+}
+
+impl Diagnostic {
+    fn new(code: DiagnosticCode, message: impl Into<String>, range: TextRange) -> Diagnostic {
+        Diagnostic {
+            code,
+            message: message.into(),
+            range,
+            severity: Severity::WeakWarning,
+            fixes: Vec::new(),
+        }
+    }
+
+    fn with_fixes(mut self, fixes: Vec<Assist>) -> Diagnostic {
+        self.fixes = fixes;
+        self
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DiagnosticsConfig {
+    /// Codes (see [`DiagnosticCode`]) that should be suppressed even though they fire.
+    pub disabled: std::collections::HashSet<String>,
+}
+
+pub struct DiagnosticsContext<'a> {
+    pub config: &'a DiagnosticsConfig,
+    pub sema: Semantics<'a, RootDatabase>,
+}
+
+/// Computes every diagnostic for `file_id`, skipping codes present in `config.disabled`.
+pub fn diagnostics(
+    db: &RootDatabase,
+    config: &DiagnosticsConfig,
+    file_id: FileId,
+) -> Vec<Diagnostic> {
+    let sema = Semantics::new(db);
+    let source_file = sema.parse(file_id);
+    let ctx = DiagnosticsContext { config, sema };
+
+    let mut res: Vec<Diagnostic> = source_file
+        .syntax()
+        .descendants()
+        .filter_map(ast::Fn::cast)
+        .filter_map(|func| handlers::manual_async_fn::manual_async_fn(&ctx, file_id, &func))
+        .collect();
+    res.retain(|diagnostic| !config.disabled.contains(diagnostic.code.0));
+    res
+}